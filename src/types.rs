@@ -1,6 +1,7 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::error::Error;
 use std::fmt;
+use std::str::FromStr;
 
 #[readonly::make]
 #[derive(Debug, Serialize, Deserialize)]
@@ -127,6 +128,99 @@ impl Entity {
     }
 }
 
+impl Entity {
+    /// The first navigational link whose `rel` contains `rel`, if any.
+    pub fn link_with_rel<'a>(&'a self, rel: &'a str) -> Option<&'a NavigationalLink> {
+        self.links_with_rel(rel).next()
+    }
+
+    /// All navigational links whose `rel` contains `rel`.
+    pub fn links_with_rel<'a>(
+        &'a self,
+        rel: &'a str,
+    ) -> impl Iterator<Item = &'a NavigationalLink> {
+        self.links
+            .iter()
+            .filter(move |link| link.rel.iter().any(|r| r == rel))
+    }
+
+    /// The `rel: ["self"]` link, if present.
+    pub fn self_href(&self) -> Option<&str> {
+        self.link_with_rel("self").map(|link| link.href.as_str())
+    }
+
+    /// The action with the given name. Action names MUST be unique within an
+    /// entity, so there is at most one match.
+    pub fn action_with_name(&self, name: &str) -> Option<&Action> {
+        self.actions.iter().find(|action| action.name == name)
+    }
+
+    /// All actions whose `class` contains `class`.
+    pub fn actions_with_class<'a>(&'a self, class: &'a str) -> impl Iterator<Item = &'a Action> {
+        self.actions
+            .iter()
+            .filter(move |action| action.class.iter().any(|c| c == class))
+    }
+
+    /// All sub-entities (embedded or linked) whose `rel` contains `rel`.
+    pub fn sub_entities_with_rel<'a>(
+        &'a self,
+        rel: &'a str,
+    ) -> impl Iterator<Item = &'a SubEntity> {
+        self.entities
+            .iter()
+            .filter(move |sub_entity| sub_entity.rel().iter().any(|r| r == rel))
+    }
+
+    /// A view over this entity's `first`/`prev`/`next`/`last` pagination
+    /// links, so a client can drive a paged resource by repeatedly following
+    /// `next()` without re-scanning `links` by hand.
+    pub fn pagination(&self) -> Pagination<'_> {
+        Pagination { entity: self }
+    }
+}
+
+/// A read-only view over the standard pagination link rels (`first`, `prev`,
+/// `next`, `last`) of an [`Entity`], as produced by [`Entity::pagination`].
+#[derive(Debug, Clone, Copy)]
+pub struct Pagination<'a> {
+    entity: &'a Entity,
+}
+
+impl<'a> Pagination<'a> {
+    pub fn first(&self) -> Option<&'a str> {
+        self.entity
+            .link_with_rel("first")
+            .map(|link| link.href.as_str())
+    }
+
+    pub fn prev(&self) -> Option<&'a str> {
+        self.entity
+            .link_with_rel("prev")
+            .map(|link| link.href.as_str())
+    }
+
+    pub fn next(&self) -> Option<&'a str> {
+        self.entity
+            .link_with_rel("next")
+            .map(|link| link.href.as_str())
+    }
+
+    pub fn last(&self) -> Option<&'a str> {
+        self.entity
+            .link_with_rel("last")
+            .map(|link| link.href.as_str())
+    }
+
+    pub fn has_prev(&self) -> bool {
+        self.prev().is_some()
+    }
+
+    pub fn has_next(&self) -> bool {
+        self.next().is_some()
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum SubEntity {
@@ -156,6 +250,15 @@ impl SubEntity {
             rel: rels.iter().map(|rel| rel.clone().into()).collect(),
         }
     }
+
+    /// The `rel` attribute of this sub-entity, required by the spec to be
+    /// non-empty for both embedded and linked sub-entities.
+    pub fn rel(&self) -> &[String] {
+        match self {
+            SubEntity::Link { inner } => &inner.rel,
+            SubEntity::Embedded { rel, .. } => rel,
+        }
+    }
 }
 
 #[readonly::make]
@@ -268,6 +371,60 @@ pub struct Action {
     pub fields: Vec<Field>,
 }
 
+impl Action {
+    pub fn new(name: impl Into<String>, href: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            class: Vec::new(),
+            method: None,
+            href: href.into(),
+            title: None,
+            _type: None,
+            fields: Vec::new(),
+        }
+    }
+
+    pub fn with_method(mut self, method: http::Method) -> Self {
+        self.method = Some(method);
+
+        self
+    }
+
+    pub fn with_class_member(mut self, class_member: impl Into<String>) -> Self {
+        self.class.push(class_member.into());
+
+        self
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+
+        self
+    }
+
+    pub fn with_type(mut self, _type: impl Into<String>) -> Self {
+        self._type = Some(_type.into());
+
+        self
+    }
+
+    pub fn with_field(mut self, field: Field) -> Self {
+        self.fields.push(field);
+
+        self
+    }
+
+    pub fn push_field(&mut self, field: Field) {
+        self.fields.push(field);
+    }
+
+    /// The field with the given name. Field names MUST be unique within an
+    /// action, so there is at most one match.
+    pub fn field_with_name(&self, name: &str) -> Option<&Field> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Field {
     /// A name describing the control. Field names MUST be unique within the set
@@ -289,12 +446,610 @@ pub struct Field {
     /// will depend on the value of the action's type attribute. See type
     /// under Actions, above. Optional.
     #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
-    pub _type: Option<String>,
-    /// A value assigned to the field. Optional.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub value: Option<String>,
+    pub _type: Option<FieldType>,
+    /// A value assigned to the field. May be a string, number, boolean, or
+    /// array, depending on the field's `type` (e.g. a `number`/`range`
+    /// input's default, a `checkbox`'s initial state, or a `radio`/multi-select
+    /// group's selection). In JSON Siren this is whatever JSON value the
+    /// server chooses to put there. Optional.
+    #[serde(default, skip_serializing_if = "serde_json::Value::is_null")]
+    pub value: serde_json::Value,
     /// Textual annotation of a field. Clients may use this as a label.
     /// Optional.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
 }
+
+impl Field {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            class: Vec::new(),
+            _type: None,
+            value: serde_json::Value::Null,
+            title: None,
+        }
+    }
+
+    pub fn with_type(mut self, _type: FieldType) -> Self {
+        self._type = Some(_type);
+
+        self
+    }
+
+    pub fn with_value(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.value = value.into();
+
+        self
+    }
+
+    /// The value as a string, if it is a JSON string.
+    pub fn as_str(&self) -> Option<&str> {
+        self.value.as_str()
+    }
+
+    /// The value as an integer, if it is a JSON number representable as
+    /// `i64`.
+    pub fn as_i64(&self) -> Option<i64> {
+        self.value.as_i64()
+    }
+
+    /// The value as a boolean, if it is a JSON boolean.
+    pub fn as_bool(&self) -> Option<bool> {
+        self.value.as_bool()
+    }
+
+    /// The value as an array, if it is a JSON array, e.g. the selection of a
+    /// multi-select field.
+    pub fn as_array(&self) -> Option<&Vec<serde_json::Value>> {
+        self.value.as_array()
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+
+        self
+    }
+
+    pub fn with_class_member(mut self, class_member: impl Into<String>) -> Self {
+        self.class.push(class_member.into());
+
+        self
+    }
+}
+
+/// The HTML5 input type of a [`Field`]. Known values are represented as their
+/// own variant so that callers can exhaustively match on them; anything the
+/// crate doesn't know about yet (a newer HTML5 type, or a server-specific
+/// extension) is preserved verbatim in `Unknown` rather than rejected, so a
+/// document can always be parsed and reserialized without losing information.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldType {
+    Hidden,
+    Text,
+    Search,
+    Tel,
+    Url,
+    Email,
+    Password,
+    Datetime,
+    Date,
+    Month,
+    Week,
+    Time,
+    DatetimeLocal,
+    Number,
+    Range,
+    Color,
+    Checkbox,
+    Radio,
+    File,
+    /// Any input type this crate doesn't have a dedicated variant for yet.
+    /// Carries the original, unrecognized token.
+    Unknown(String),
+}
+
+impl FieldType {
+    fn as_str(&self) -> &str {
+        match self {
+            FieldType::Hidden => "hidden",
+            FieldType::Text => "text",
+            FieldType::Search => "search",
+            FieldType::Tel => "tel",
+            FieldType::Url => "url",
+            FieldType::Email => "email",
+            FieldType::Password => "password",
+            FieldType::Datetime => "datetime",
+            FieldType::Date => "date",
+            FieldType::Month => "month",
+            FieldType::Week => "week",
+            FieldType::Time => "time",
+            FieldType::DatetimeLocal => "datetime-local",
+            FieldType::Number => "number",
+            FieldType::Range => "range",
+            FieldType::Color => "color",
+            FieldType::Checkbox => "checkbox",
+            FieldType::Radio => "radio",
+            FieldType::File => "file",
+            FieldType::Unknown(s) => s,
+        }
+    }
+}
+
+impl fmt::Display for FieldType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for FieldType {
+    /// Never actually returned: unrecognized tokens fall back to `Unknown`
+    /// rather than failing to parse.
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "hidden" => FieldType::Hidden,
+            "text" => FieldType::Text,
+            "search" => FieldType::Search,
+            "tel" => FieldType::Tel,
+            "url" => FieldType::Url,
+            "email" => FieldType::Email,
+            "password" => FieldType::Password,
+            "datetime" => FieldType::Datetime,
+            "date" => FieldType::Date,
+            "month" => FieldType::Month,
+            "week" => FieldType::Week,
+            "time" => FieldType::Time,
+            "datetime-local" => FieldType::DatetimeLocal,
+            "number" => FieldType::Number,
+            "range" => FieldType::Range,
+            "color" => FieldType::Color,
+            "checkbox" => FieldType::Checkbox,
+            "radio" => FieldType::Radio,
+            "file" => FieldType::File,
+            other => FieldType::Unknown(other.to_string()),
+        })
+    }
+}
+
+impl Serialize for FieldType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for FieldType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+
+        // Infallible: unrecognized tokens become `FieldType::Unknown`.
+        Ok(s.parse().unwrap())
+    }
+}
+
+/// A machine-readable code for a single violation of one of Siren's
+/// structural invariants, as found by [`Entity::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SirenValidationErrorKind {
+    /// An embedded sub-entity or entity link's `rel` MUST be non-empty.
+    EmptyRel,
+    /// Action `name` values MUST be unique within an entity.
+    DuplicateActionName,
+    /// Field `name` values MUST be unique within an action.
+    DuplicateFieldName,
+    /// An `href` MUST be present.
+    MissingHref,
+}
+
+/// A single violation of one of Siren's structural invariants, located by
+/// its `path` from the root entity (e.g. `$.entities[2].actions[0].fields[1]`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SirenValidationError {
+    pub path: String,
+    pub kind: SirenValidationErrorKind,
+}
+
+impl fmt::Display for SirenValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let desc = match self.kind {
+            SirenValidationErrorKind::EmptyRel => "rel MUST be a non-empty array of strings",
+            SirenValidationErrorKind::DuplicateActionName => {
+                "action names MUST be unique within an entity"
+            }
+            SirenValidationErrorKind::DuplicateFieldName => {
+                "field names MUST be unique within an action"
+            }
+            SirenValidationErrorKind::MissingHref => "href MUST be present",
+        };
+
+        write!(f, "{}: {}", self.path, desc)
+    }
+}
+
+impl std::error::Error for SirenValidationError {}
+
+impl Entity {
+    /// Walks this entity and all of its embedded sub-entities, reporting
+    /// every violation of Siren's structural invariants (non-empty `rel` on
+    /// sub-entities, unique action names, unique field names, present
+    /// `href`s). Servers can use this to assert they emit conformant
+    /// documents; clients can use it to reject malformed input early.
+    pub fn validate(&self) -> Result<(), Vec<SirenValidationError>> {
+        let mut errors = Vec::new();
+
+        self.validate_at("$", &mut errors);
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn validate_at(&self, path: &str, errors: &mut Vec<SirenValidationError>) {
+        let mut seen_action_names = std::collections::HashSet::new();
+        for (i, action) in self.actions.iter().enumerate() {
+            let action_path = format!("{}.actions[{}]", path, i);
+
+            if !seen_action_names.insert(action.name.as_str()) {
+                errors.push(SirenValidationError {
+                    path: action_path.clone(),
+                    kind: SirenValidationErrorKind::DuplicateActionName,
+                });
+            }
+            if action.href.is_empty() {
+                errors.push(SirenValidationError {
+                    path: action_path.clone(),
+                    kind: SirenValidationErrorKind::MissingHref,
+                });
+            }
+
+            let mut seen_field_names = std::collections::HashSet::new();
+            for (j, field) in action.fields.iter().enumerate() {
+                if !seen_field_names.insert(field.name.as_str()) {
+                    errors.push(SirenValidationError {
+                        path: format!("{}.fields[{}]", action_path, j),
+                        kind: SirenValidationErrorKind::DuplicateFieldName,
+                    });
+                }
+            }
+        }
+
+        for (i, link) in self.links.iter().enumerate() {
+            if link.href.is_empty() {
+                errors.push(SirenValidationError {
+                    path: format!("{}.links[{}]", path, i),
+                    kind: SirenValidationErrorKind::MissingHref,
+                });
+            }
+        }
+
+        for (i, sub_entity) in self.entities.iter().enumerate() {
+            let sub_path = format!("{}.entities[{}]", path, i);
+
+            if sub_entity.rel().is_empty() {
+                errors.push(SirenValidationError {
+                    path: sub_path.clone(),
+                    kind: SirenValidationErrorKind::EmptyRel,
+                });
+            }
+
+            match sub_entity {
+                SubEntity::Link { inner } => {
+                    if inner.href.is_empty() {
+                        errors.push(SirenValidationError {
+                            path: sub_path,
+                            kind: SirenValidationErrorKind::MissingHref,
+                        });
+                    }
+                }
+                SubEntity::Embedded { inner, .. } => {
+                    inner.validate_at(&sub_path, errors);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entity_query_helpers_find_links_actions_and_sub_entities() {
+        let mut entity = Entity::default()
+            .with_link(NavigationalLink::new(
+                &["self"],
+                "http://api.x.io/orders/1234",
+            ))
+            .with_link(NavigationalLink::new(
+                &["items"],
+                "http://api.x.io/orders/1234/items",
+            ))
+            .with_link(NavigationalLink::new(
+                &["items"],
+                "http://api.x.io/orders/1234/items2",
+            ))
+            .with_action(
+                Action::new("add-item", "http://api.x.io/orders/1234/items")
+                    .with_class_member("create-action"),
+            );
+        entity.push_sub_entity(SubEntity::from_entity(Entity::default(), &["customer"]));
+
+        assert_eq!(entity.self_href(), Some("http://api.x.io/orders/1234"));
+        assert_eq!(
+            entity.link_with_rel("items").map(|link| link.href.as_str()),
+            Some("http://api.x.io/orders/1234/items")
+        );
+        assert_eq!(entity.links_with_rel("items").count(), 2);
+        assert!(entity.link_with_rel("missing").is_none());
+
+        assert_eq!(
+            entity
+                .action_with_name("add-item")
+                .map(|action| action.href.as_str()),
+            Some("http://api.x.io/orders/1234/items")
+        );
+        assert!(entity.action_with_name("missing").is_none());
+        assert_eq!(entity.actions_with_class("create-action").count(), 1);
+
+        assert_eq!(entity.sub_entities_with_rel("customer").count(), 1);
+        assert_eq!(entity.sub_entities_with_rel("missing").count(), 0);
+    }
+
+    #[test]
+    fn field_value_typed_accessors_read_the_underlying_json_value() {
+        assert_eq!(
+            Field::new("name").with_value("Kevin").as_str(),
+            Some("Kevin")
+        );
+        assert_eq!(Field::new("age").with_value(30).as_i64(), Some(30));
+        assert_eq!(Field::new("active").with_value(true).as_bool(), Some(true));
+        assert_eq!(
+            Field::new("colors")
+                .with_value(vec!["red", "green"])
+                .as_array(),
+            Some(&vec![
+                serde_json::Value::String("red".to_string()),
+                serde_json::Value::String("green".to_string())
+            ])
+        );
+
+        assert_eq!(Field::new("name").with_value("Kevin").as_i64(), None);
+    }
+
+    #[test]
+    fn field_value_is_skipped_when_serializing_if_null() {
+        let field = Field::new("name");
+        assert_eq!(field.value, serde_json::Value::Null);
+
+        let json = serde_json::to_string(&field).unwrap();
+        assert!(!json.contains("value"));
+    }
+
+    #[test]
+    fn field_type_round_trips_a_known_variant_verbatim() {
+        let field = Field::new("due_date").with_type(FieldType::Date);
+
+        let json = serde_json::to_string(&field).unwrap();
+        assert!(json.contains("\"type\":\"date\""));
+
+        let parsed: Field = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed._type, Some(FieldType::Date));
+    }
+
+    #[test]
+    fn field_type_round_trips_an_unknown_token_verbatim() {
+        let json = r#"{"name":"color","type":"swatch-picker"}"#;
+
+        let field: Field = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            field._type,
+            Some(FieldType::Unknown("swatch-picker".to_string()))
+        );
+
+        let reserialized = serde_json::to_string(&field).unwrap();
+        assert!(reserialized.contains("\"type\":\"swatch-picker\""));
+    }
+
+    #[test]
+    fn action_with_method_accepts_non_standard_extension_methods() {
+        let lock = http::Method::from_bytes(b"LOCK").unwrap();
+        let action = Action::new("lock", "http://api.x.io/orders/1234").with_method(lock.clone());
+
+        assert_eq!(action.method, Some(lock));
+    }
+
+    #[test]
+    fn action_and_field_builders_produce_the_expected_struct() {
+        let action = Action::new("add-item", "http://api.x.io/orders/1234/items")
+            .with_method(http::Method::POST)
+            .with_class_member("create-action")
+            .with_title("Add Item")
+            .with_type("application/x-www-form-urlencoded")
+            .with_field(
+                Field::new("quantity")
+                    .with_type(FieldType::Number)
+                    .with_value(1)
+                    .with_title("Quantity")
+                    .with_class_member("positive-integer"),
+            );
+
+        assert_eq!(action.name, "add-item");
+        assert_eq!(action.href, "http://api.x.io/orders/1234/items");
+        assert_eq!(action.method, Some(http::Method::POST));
+        assert_eq!(action.class, vec!["create-action".to_string()]);
+        assert_eq!(action.title, Some("Add Item".to_string()));
+        assert_eq!(
+            action._type,
+            Some("application/x-www-form-urlencoded".to_string())
+        );
+
+        let field = &action.fields[0];
+        assert_eq!(field.name, "quantity");
+        assert_eq!(field._type, Some(FieldType::Number));
+        assert_eq!(field.value, serde_json::json!(1));
+        assert_eq!(field.title, Some("Quantity".to_string()));
+        assert_eq!(field.class, vec!["positive-integer".to_string()]);
+    }
+
+    #[test]
+    fn action_push_field_appends_without_consuming_the_action() {
+        let mut action = Action::new("add-item", "http://api.x.io/orders/1234/items");
+        action.push_field(Field::new("quantity"));
+
+        assert_eq!(
+            action.field_with_name("quantity").map(|f| f.name.as_str()),
+            Some("quantity")
+        );
+    }
+
+    #[test]
+    fn pagination_resolves_the_standard_collection_rels() {
+        let entity = Entity::default()
+            .with_link(NavigationalLink::new(
+                &["first"],
+                "http://api.x.io/orders?page=1",
+            ))
+            .with_link(NavigationalLink::new(
+                &["prev"],
+                "http://api.x.io/orders?page=2",
+            ))
+            .with_link(NavigationalLink::new(
+                &["next"],
+                "http://api.x.io/orders?page=4",
+            ))
+            .with_link(NavigationalLink::new(
+                &["last"],
+                "http://api.x.io/orders?page=10",
+            ));
+
+        let pagination = entity.pagination();
+
+        assert_eq!(pagination.first(), Some("http://api.x.io/orders?page=1"));
+        assert_eq!(pagination.prev(), Some("http://api.x.io/orders?page=2"));
+        assert_eq!(pagination.next(), Some("http://api.x.io/orders?page=4"));
+        assert_eq!(pagination.last(), Some("http://api.x.io/orders?page=10"));
+        assert!(pagination.has_prev());
+        assert!(pagination.has_next());
+    }
+
+    #[test]
+    fn pagination_reports_no_prev_or_next_on_a_single_page() {
+        let entity = Entity::default().with_link(NavigationalLink::new(
+            &["self"],
+            "http://api.x.io/orders?page=1",
+        ));
+
+        let pagination = entity.pagination();
+
+        assert_eq!(pagination.prev(), None);
+        assert_eq!(pagination.next(), None);
+        assert!(!pagination.has_prev());
+        assert!(!pagination.has_next());
+    }
+
+    #[test]
+    fn validate_accepts_a_conformant_entity() {
+        let entity = Entity::default()
+            .with_link(NavigationalLink::new(
+                &["self"],
+                "http://api.x.io/orders/1234",
+            ))
+            .with_action(Action::new("add-item", "http://api.x.io/orders/1234/items"));
+
+        assert!(entity.validate().is_ok());
+    }
+
+    #[test]
+    fn validate_reports_empty_rel_on_a_sub_entity() {
+        let mut entity = Entity::default();
+        entity.push_sub_entity(SubEntity::from_entity(Entity::default(), &[] as &[String]));
+
+        let errors = entity.validate().unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec![SirenValidationError {
+                path: "$.entities[0]".to_string(),
+                kind: SirenValidationErrorKind::EmptyRel,
+            }]
+        );
+    }
+
+    #[test]
+    fn validate_reports_duplicate_action_names() {
+        let entity = Entity::default()
+            .with_action(Action::new("add-item", "http://api.x.io/orders/1234/items"))
+            .with_action(Action::new("add-item", "http://api.x.io/orders/1234/items"));
+
+        let errors = entity.validate().unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec![SirenValidationError {
+                path: "$.actions[1]".to_string(),
+                kind: SirenValidationErrorKind::DuplicateActionName,
+            }]
+        );
+    }
+
+    #[test]
+    fn validate_reports_duplicate_field_names() {
+        let action = Action::new("add-item", "http://api.x.io/orders/1234/items")
+            .with_field(Field::new("quantity"))
+            .with_field(Field::new("quantity"));
+        let entity = Entity::default().with_action(action);
+
+        let errors = entity.validate().unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec![SirenValidationError {
+                path: "$.actions[0].fields[1]".to_string(),
+                kind: SirenValidationErrorKind::DuplicateFieldName,
+            }]
+        );
+    }
+
+    #[test]
+    fn validate_reports_missing_href() {
+        let entity = Entity::default().with_action(Action::new("add-item", ""));
+
+        let errors = entity.validate().unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec![SirenValidationError {
+                path: "$.actions[0]".to_string(),
+                kind: SirenValidationErrorKind::MissingHref,
+            }]
+        );
+    }
+
+    #[test]
+    fn validate_walks_nested_embedded_entities() {
+        let inner = Entity::default()
+            .with_action(Action::new("add-item", "http://api.x.io/orders/1234/items"))
+            .with_action(Action::new("add-item", "http://api.x.io/orders/1234/items"));
+        let mut entity = Entity::default();
+        entity.push_sub_entity(SubEntity::from_entity(inner, &["item"]));
+
+        let errors = entity.validate().unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec![SirenValidationError {
+                path: "$.entities[0].actions[1]".to_string(),
+                kind: SirenValidationErrorKind::DuplicateActionName,
+            }]
+        );
+    }
+}